@@ -20,6 +20,9 @@ use prettytable::cell::Cell;
 use prettytable::row::Row;
 use prettytable::{format, Table};
 use regex::Regex;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::borrow::Cow;
+use std::cmp::Ordering;
 
 enum CellSpecTxt<'a> {
     Index,
@@ -32,6 +35,7 @@ pub struct CellSpec<'a> {
     txt: CellSpecTxt<'a>,
     pub style: Option<&'a str>,
     pub align: Option<format::Alignment>,
+    column: Option<&'a str>,
 }
 
 impl<'a> CellSpec<'a> {
@@ -40,6 +44,7 @@ impl<'a> CellSpec<'a> {
             txt: CellSpecTxt::Str(txt),
             style: None,
             align: None,
+            column: None,
         }
     }
 
@@ -48,6 +53,7 @@ impl<'a> CellSpec<'a> {
             txt: CellSpecTxt::String(txt),
             style: None,
             align: None,
+            column: None,
         }
     }
 
@@ -56,6 +62,7 @@ impl<'a> CellSpec<'a> {
             txt: CellSpecTxt::Index,
             style: None,
             align: None,
+            column: None,
         }
     }
 
@@ -64,6 +71,7 @@ impl<'a> CellSpec<'a> {
             txt: CellSpecTxt::Str(txt),
             style: Some(style),
             align: None,
+            column: None,
         }
     }
 
@@ -72,9 +80,23 @@ impl<'a> CellSpec<'a> {
             txt: CellSpecTxt::String(txt),
             style: Some(style),
             align: None,
+            column: None,
         }
     }
 
+    /// Tags this cell with the name of the column header it falls under, so
+    /// filter, style and transform rules can address it by name instead of
+    /// position. `sort` does not read this tag; it finds columns by header.
+    pub fn with_column(mut self, column: &'a str) -> CellSpec<'a> {
+        self.column = Some(column);
+        self
+    }
+
+    /// Replaces this cell's text in place, leaving style/align/column untouched.
+    fn set_text(&mut self, txt: String) {
+        self.txt = CellSpecTxt::String(txt);
+    }
+
     pub fn to_cell(&self, index: usize) -> Cell {
         let cell = match self.txt {
             CellSpecTxt::Index => Cell::new(format!("{}", index).as_str()),
@@ -89,44 +111,743 @@ impl<'a> CellSpec<'a> {
         }
     }
 
+    /// The resolved text of this cell, or None for the synthetic index column.
+    fn text(&self) -> Option<&str> {
+        match self.txt {
+            CellSpecTxt::Index => None,
+            CellSpecTxt::Str(s) => Some(s),
+            CellSpecTxt::String(ref s) => Some(s.as_str()),
+        }
+    }
+
     pub fn matches(&self, regex: &Regex) -> bool {
+        self.text().is_some_and(|s| regex.is_match(s))
+    }
+
+    /// The cell's fully resolved text, independent of any prettytable styling.
+    pub fn resolved_text(&self, index: usize) -> String {
         match self.txt {
-            CellSpecTxt::Index => false,
-            CellSpecTxt::Str(ref s) => regex.is_match(s),
-            CellSpecTxt::String(ref s) => regex.is_match(s),
+            CellSpecTxt::Index => format!("{}", index),
+            CellSpecTxt::Str(ref s) => s.to_string(),
+            CellSpecTxt::String(ref s) => s.clone(),
         }
     }
 }
 
-pub fn get_regex(matches: &ArgMatches) -> Result<Option<Regex>, String> {
-    match matches.value_of("regex") {
-        Some(pattern) => {
-            if let Ok(regex) = Regex::new(pattern) {
-                Ok(Some(regex))
+/// Stamps each cell in a row with the name of its column, reading positionally
+/// from the table's header row. Needed before a row can be matched against a
+/// column-scoped [`Query`] or [`StyleRule`].
+pub fn label_columns<'a>(headers: &[&'a str], row: Vec<CellSpec<'a>>) -> Vec<CellSpec<'a>> {
+    row.into_iter()
+        .enumerate()
+        .map(|(i, spec)| match headers.get(i) {
+            Some(header) => spec.with_column(header),
+            None => spec,
+        })
+        .collect()
+}
+
+/// A comparison a [`Predicate`] can apply between a cell's text and a pattern.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Op {
+    Eq,
+    Ne,
+    RegexMatch,
+    RegexNotMatch,
+    Lt,
+    Gt,
+    Le,
+    Ge,
+}
+
+const OPS: &[(&str, Op)] = &[
+    ("=~", Op::RegexMatch),
+    ("!~", Op::RegexNotMatch),
+    ("==", Op::Eq),
+    ("!=", Op::Ne),
+    ("<=", Op::Le),
+    (">=", Op::Ge),
+    ("<", Op::Lt),
+    (">", Op::Gt),
+];
+
+/// A single column-scoped comparison parsed out of a `--filter` expression,
+/// e.g. `status==Running` or `restarts>3`. A predicate with no column (a bare
+/// pattern, the old behavior) matches if any cell in the row matches.
+struct Predicate {
+    column: Option<String>,
+    op: Op,
+    pattern: String,
+    regex: Option<Regex>,
+}
+
+impl Predicate {
+    fn parse(text: &str) -> Result<Predicate, String> {
+        let text = text.trim();
+
+        // Find the earliest operator in the text; on a tie for position,
+        // prefer the longer token (so "<=" wins over "<").
+        let found = OPS
+            .iter()
+            .filter_map(|&(tok, op)| text.find(tok).map(|idx| (idx, tok.len(), op)))
+            .min_by_key(|&(idx, len, _)| (idx, std::cmp::Reverse(len)));
+
+        let (column, op, pattern) = match found {
+            Some((idx, len, op)) => {
+                let column = text[..idx].trim();
+                let pattern = text[idx + len..].trim();
+                (Some(column.to_string()), op, pattern.to_string())
+            }
+            None => (None, Op::RegexMatch, text.to_string()),
+        };
+
+        let regex = match op {
+            Op::RegexMatch | Op::RegexNotMatch => Some(
+                Regex::new(&pattern).map_err(|_| format!("Invalid regex: {}", pattern))?,
+            ),
+            _ => None,
+        };
+
+        Ok(Predicate {
+            column,
+            op,
+            pattern,
+            regex,
+        })
+    }
+
+    fn cell_matches(&self, cell: &CellSpec) -> bool {
+        let text = match cell.text() {
+            Some(text) => text,
+            None => return false,
+        };
+        match self.op {
+            Op::Eq => text == self.pattern,
+            Op::Ne => text != self.pattern,
+            Op::RegexMatch => self.regex.as_ref().is_some_and(|r| r.is_match(text)),
+            Op::RegexNotMatch => self.regex.as_ref().is_some_and(|r| !r.is_match(text)),
+            Op::Lt | Op::Gt | Op::Le | Op::Ge => {
+                Predicate::numeric_cmp(text, &self.pattern, self.op)
+            }
+        }
+    }
+
+    fn numeric_cmp(lhs: &str, rhs: &str, op: Op) -> bool {
+        fn as_f64(s: &str) -> Option<f64> {
+            s.parse::<i64>()
+                .map(|v| v as f64)
+                .ok()
+                .or_else(|| s.parse::<f64>().ok())
+        }
+        match (as_f64(lhs), as_f64(rhs)) {
+            (Some(l), Some(r)) => match op {
+                Op::Lt => l < r,
+                Op::Gt => l > r,
+                Op::Le => l <= r,
+                Op::Ge => l >= r,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn row_matches(&self, row: &[CellSpec]) -> bool {
+        match &self.column {
+            Some(col) => row
+                .iter()
+                .filter(|cell| cell.column == Some(col.as_str()))
+                .any(|cell| self.cell_matches(cell)),
+            None => row.iter().any(|cell| self.cell_matches(cell)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod predicate_tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_bare_pattern_with_no_operator() {
+        let predicate = Predicate::parse("web-.*").unwrap();
+        assert!(predicate.column.is_none());
+        assert!(predicate.op == Op::RegexMatch);
+        assert_eq!(predicate.pattern, "web-.*");
+    }
+
+    #[test]
+    fn picks_the_earliest_operator() {
+        // "restarts>3==4" could match at the ">" (idx 8) or the "=="
+        // inside "3==4" (idx 10); the earliest position wins.
+        let predicate = Predicate::parse("restarts>3==4").unwrap();
+        assert_eq!(predicate.column.as_deref(), Some("restarts"));
+        assert!(predicate.op == Op::Gt);
+        assert_eq!(predicate.pattern, "3==4");
+    }
+
+    #[test]
+    fn prefers_the_longer_token_on_a_tied_position() {
+        // "<=" and "<" both start at idx 8 here; the longer token must win,
+        // leaving "3" rather than "=3" as the pattern.
+        let le = Predicate::parse("restarts<=3").unwrap();
+        assert!(le.op == Op::Le);
+        assert_eq!(le.pattern, "3");
+
+        // The two-character operators that share no starting character with
+        // a shorter token each parse as themselves.
+        let predicate = Predicate::parse("status==Running").unwrap();
+        assert!(predicate.op == Op::Eq);
+        assert_eq!(predicate.pattern, "Running");
+
+        let ne = Predicate::parse("status!=Running").unwrap();
+        assert!(ne.op == Op::Ne);
+        let not_match = Predicate::parse("status!~Running").unwrap();
+        assert!(not_match.op == Op::RegexNotMatch);
+    }
+
+    #[test]
+    fn le_and_ge_are_not_mistaken_for_lt_and_gt() {
+        let le = Predicate::parse("restarts<=3").unwrap();
+        assert!(le.op == Op::Le);
+        assert_eq!(le.pattern, "3");
+
+        let ge = Predicate::parse("restarts>=3").unwrap();
+        assert!(ge.op == Op::Ge);
+        assert_eq!(ge.pattern, "3");
+    }
+
+    #[test]
+    fn rejects_an_invalid_regex_pattern() {
+        assert!(Predicate::parse("name=~(").is_err());
+    }
+}
+
+/// Checks a parsed column reference against the table's real headers,
+/// returning a descriptive error for a typo'd or unknown column name.
+fn validate_column(headers: &[&str], column: &Option<String>) -> Result<(), String> {
+    match column.as_deref().filter(|column| !headers.contains(column)) {
+        Some(column) => Err(format!("Unknown column: {}", column)),
+        None => Ok(()),
+    }
+}
+
+/// Splits `text` on `sep` at the top level only, ignoring any separator
+/// nested inside `()`, `[]`, or `{}` — so a regex bounded quantifier like
+/// `\d{2,4}` isn't cut in half when splitting a comma-separated predicate
+/// list.
+fn split_top_level(text: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            _ if c == sep && depth <= 0 => {
+                parts.push(&text[start..i]);
+                start = i + c.len_utf8();
             }
-            else {
-                Err(format!("Invalid regex: {}", pattern))
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// A parsed `--filter` expression, e.g. `name=~web-.*,status==Running||restarts>3`.
+/// Comma-separated predicates are ANDed together into a group; `||`-separated
+/// groups are ORed.
+pub struct Query(Vec<Vec<Predicate>>);
+
+impl Query {
+    pub fn parse(headers: &[&str], query: &str) -> Result<Query, String> {
+        let mut groups = Vec::new();
+        for group in query.split("||") {
+            let mut predicates = Vec::new();
+            for part in split_top_level(group, ',') {
+                let part = part.trim();
+                if part.is_empty() {
+                    continue;
+                }
+                let predicate = Predicate::parse(part)?;
+                validate_column(headers, &predicate.column)?;
+                predicates.push(predicate);
             }
+            groups.push(predicates);
         }
+        Ok(Query(groups))
+    }
+
+    fn matches(&self, row: &[CellSpec]) -> bool {
+        self.0
+            .iter()
+            .any(|group| group.iter().all(|predicate| predicate.row_matches(row)))
+    }
+}
+
+#[cfg(test)]
+mod query_tests {
+    use super::*;
+
+    #[test]
+    fn splits_anded_predicates_on_commas() {
+        let query = Query::parse(&["name", "status"], "name==web,status==Running").unwrap();
+        assert_eq!(query.0.len(), 1);
+        assert_eq!(query.0[0].len(), 2);
+    }
+
+    #[test]
+    fn splits_ored_groups_on_double_pipes() {
+        let query = Query::parse(&["status"], "status==Running||status==Pending").unwrap();
+        assert_eq!(query.0.len(), 2);
+    }
+
+    #[test]
+    fn does_not_split_a_comma_inside_a_bounded_quantifier() {
+        let query = Query::parse(&["name"], r"name=~web-\d{2,4}").unwrap();
+        assert_eq!(query.0.len(), 1);
+        assert_eq!(query.0[0].len(), 1);
+        assert_eq!(query.0[0][0].pattern, r"web-\d{2,4}");
+    }
+
+    #[test]
+    fn a_bounded_quantifier_predicate_still_combines_with_an_anded_sibling() {
+        let query = Query::parse(&["name", "status"], r"name=~web-\d{2,4},status==Running").unwrap();
+        assert_eq!(query.0.len(), 1);
+        assert_eq!(query.0[0].len(), 2);
+        assert_eq!(query.0[0][0].pattern, r"web-\d{2,4}");
+        assert_eq!(query.0[0][1].pattern, "Running");
+    }
+
+    #[test]
+    fn rejects_an_unknown_column() {
+        assert!(Query::parse(&["status"], "stauts==Running").is_err());
+    }
+}
+
+/// Reads the `--filter` argument (if any) and parses it into a [`Query`]
+/// against the given column headers.
+pub fn get_query(matches: &ArgMatches, headers: &[&str]) -> Result<Option<Query>, String> {
+    match matches.value_of("filter") {
+        Some(query) => Query::parse(headers, query).map(Some),
         None => Ok(None),
     }
 }
 
-pub fn filter<'a, T, I> (things: I, regex: Regex) -> Vec<(T, Vec<CellSpec<'a>>)>
+pub fn filter<'a, T, I>(things: I, query: &Query) -> Vec<(T, Vec<CellSpec<'a>>)>
     where I: Iterator<Item=(T,Vec<CellSpec<'a>>)> {
-    things.filter_map(|thing| {
-        let mut has_match = false;
-        for cell_spec in thing.1.iter() {
-            if !has_match {
-                has_match = cell_spec.matches(&regex);
+    things.filter(|thing| query.matches(&thing.1)).collect()
+}
+
+/// A single `--sort-by` key, e.g. `status` or `-age` (leading `-` = descending).
+pub struct SortKey {
+    column: String,
+    descending: bool,
+}
+
+/// Reads the `--sort-by` argument (if any), parsing a comma-separated list of
+/// column names like `status,-age` into [`SortKey`]s.
+pub fn get_sort_keys(matches: &ArgMatches) -> Option<Vec<SortKey>> {
+    matches.value_of("sort-by").map(|raw| {
+        raw.split(',')
+            .map(|part| {
+                let part = part.trim();
+                match part.strip_prefix('-') {
+                    Some(column) => SortKey { column: column.to_string(), descending: true },
+                    None => SortKey { column: part.to_string(), descending: false },
+                }
+            })
+            .collect()
+    })
+}
+
+/// Sorts rows by one or more named columns, stably, running before
+/// `add_to_table` renumbers the index column so it reflects post-sort order.
+/// A key's column is sorted numerically if every row's value there parses as
+/// a number, and lexicographically otherwise.
+pub fn sort<'a, T>(specs: &mut Vec<(T, Vec<CellSpec<'a>>)>, headers: &[&str], keys: &[SortKey]) -> Result<(), String> {
+    let resolved: Vec<(usize, bool)> = keys.iter().map(|key| {
+        headers.iter().position(|header| *header == key.column)
+            .map(|idx| (idx, key.descending))
+            .ok_or_else(|| format!("Unknown column: {}", key.column))
+    }).collect::<Result<_, _>>()?;
+
+    let texts: Vec<Vec<String>> = specs.iter().enumerate()
+        .map(|(index, t_spec)| {
+            resolved.iter()
+                .map(|&(col, _)| t_spec.1.get(col).map_or(String::new(), |cell| cell.resolved_text(index)))
+                .collect()
+        })
+        .collect();
+
+    let numeric: Vec<bool> = (0..resolved.len())
+        .map(|key| texts.iter().all(|row| row[key].parse::<f64>().is_ok()))
+        .collect();
+
+    let mut order: Vec<usize> = (0..specs.len()).collect();
+    order.sort_by(|&a, &b| {
+        for (key, &(_, descending)) in resolved.iter().enumerate() {
+            let ord = if numeric[key] {
+                let a_val: f64 = texts[a][key].parse().unwrap_or(0.0);
+                let b_val: f64 = texts[b][key].parse().unwrap_or(0.0);
+                a_val.partial_cmp(&b_val).unwrap_or(Ordering::Equal)
+            } else {
+                texts[a][key].cmp(&texts[b][key])
+            };
+            let ord = if descending { ord.reverse() } else { ord };
+            if ord != Ordering::Equal {
+                return ord;
             }
         }
-        if has_match {
-            Some(thing)
-        } else {
-            None
+        Ordering::Equal
+    });
+
+    let mut rows: Vec<Option<(T, Vec<CellSpec<'a>>)>> = specs.drain(..).map(Some).collect();
+    for index in order {
+        specs.push(rows[index].take().expect("sort order indexes each row exactly once"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod sort_tests {
+    use super::*;
+
+    fn row(name: &str) -> Vec<CellSpec<'static>> {
+        vec![CellSpec::new_owned(name.to_string())]
+    }
+
+    #[test]
+    fn sorts_numerically_when_every_value_parses_as_a_number() {
+        let headers = ["restarts"];
+        let mut specs = vec![
+            ((), row("10")),
+            ((), row("2")),
+            ((), row("1")),
+        ];
+        sort(&mut specs, &headers, &[SortKey { column: "restarts".to_string(), descending: false }]).unwrap();
+        let order: Vec<&str> = specs.iter().map(|(_, cells)| cells[0].text().unwrap()).collect();
+        // Numeric order (1, 2, 10), not lexicographic ("1", "10", "2").
+        assert_eq!(order, vec!["1", "2", "10"]);
+    }
+
+    #[test]
+    fn falls_back_to_lexicographic_when_any_value_is_not_numeric() {
+        let headers = ["status"];
+        let mut specs = vec![
+            ((), row("10")),
+            ((), row("2")),
+            ((), row("Running")),
+        ];
+        sort(&mut specs, &headers, &[SortKey { column: "status".to_string(), descending: false }]).unwrap();
+        let order: Vec<&str> = specs.iter().map(|(_, cells)| cells[0].text().unwrap()).collect();
+        assert_eq!(order, vec!["10", "2", "Running"]);
+    }
+
+    #[test]
+    fn a_leading_dash_key_sorts_descending() {
+        let headers = ["restarts"];
+        let mut specs = vec![
+            ((), row("1")),
+            ((), row("3")),
+            ((), row("2")),
+        ];
+        sort(&mut specs, &headers, &[SortKey { column: "restarts".to_string(), descending: true }]).unwrap();
+        let order: Vec<&str> = specs.iter().map(|(_, cells)| cells[0].text().unwrap()).collect();
+        assert_eq!(order, vec!["3", "2", "1"]);
+    }
+
+    #[test]
+    fn sort_is_stable_on_ties() {
+        let headers = ["status"];
+        let mut specs = vec![
+            (1, row("Running")),
+            (2, row("Running")),
+            (3, row("Running")),
+        ];
+        sort(&mut specs, &headers, &[SortKey { column: "status".to_string(), descending: false }]).unwrap();
+        let order: Vec<i32> = specs.iter().map(|(id, _)| *id).collect();
+        assert_eq!(order, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn rejects_an_unknown_sort_column() {
+        let headers = ["status"];
+        let mut specs: Vec<((), Vec<CellSpec>)> = vec![((), row("Running"))];
+        let err = sort(&mut specs, &headers, &[SortKey { column: "stauts".to_string(), descending: false }]).unwrap_err();
+        assert_eq!(err, "Unknown column: stauts");
+    }
+}
+
+/// Where a matching [`StyleRule`] applies its style.
+pub enum Scope {
+    Cell,
+    Row,
+}
+
+/// A conditional coloring rule supplied via a repeated `--color` flag, e.g.
+/// `status!=Running:row:Frred` paints the whole row when status isn't Running.
+pub struct StyleRule<'a> {
+    predicate: Predicate,
+    scope: Scope,
+    style: &'a str,
+}
+
+impl<'a> StyleRule<'a> {
+    pub fn parse(text: &'a str, headers: &[&str]) -> Result<StyleRule<'a>, String> {
+        let mut parts = text.rsplitn(3, ':');
+        let style = parts.next().ok_or_else(|| format!("Invalid style rule: {}", text))?;
+        let scope = parts.next().ok_or_else(|| format!("Invalid style rule: {}", text))?;
+        let predicate_text = parts.next().ok_or_else(|| format!("Invalid style rule: {}", text))?;
+
+        let scope = match scope {
+            "row" => Scope::Row,
+            "cell" => Scope::Cell,
+            other => return Err(format!("Unknown style rule scope: {}", other)),
+        };
+
+        let predicate = Predicate::parse(predicate_text)?;
+        validate_column(headers, &predicate.column)?;
+
+        Ok(StyleRule { predicate, scope, style })
+    }
+}
+
+/// Reads the repeated `--color` argument (if any) into a list of [`StyleRule`]s.
+pub fn get_style_rules<'a>(matches: &'a ArgMatches, headers: &[&str]) -> Result<Vec<StyleRule<'a>>, String> {
+    match matches.values_of("color") {
+        Some(values) => values.map(|text| StyleRule::parse(text, headers)).collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Applies conditional coloring rules to a table of resources, overriding
+/// `CellSpec::style` on matching cells or whole rows. Rules are evaluated in
+/// order, so a later matching rule wins over an earlier one. Has no effect
+/// on non-table output formats, which never read `style` in the first place.
+pub fn apply_styles<'a, T>(specs: &mut Vec<(T, Vec<CellSpec<'a>>)>, rules: &[StyleRule<'a>]) {
+    for t_spec in specs.iter_mut() {
+        for rule in rules {
+            if !rule.predicate.row_matches(&t_spec.1) {
+                continue;
+            }
+            for cell in t_spec.1.iter_mut() {
+                match rule.scope {
+                    Scope::Row => cell.style = Some(rule.style),
+                    Scope::Cell => {
+                        let in_column = match &rule.predicate.column {
+                            Some(column) => cell.column == Some(column.as_str()),
+                            None => true,
+                        };
+                        if in_column && rule.predicate.cell_matches(cell) {
+                            cell.style = Some(rule.style);
+                        }
+                    }
+                }
+            }
         }
-    }).collect()
+    }
+}
+
+#[cfg(test)]
+mod style_rule_tests {
+    use super::*;
+
+    fn row<'a>(headers: &[&'a str], values: &[&'a str]) -> Vec<CellSpec<'a>> {
+        label_columns(headers, values.iter().map(|v| CellSpec::new(v)).collect())
+    }
+
+    #[test]
+    fn parses_a_row_scoped_rule() {
+        let rule = StyleRule::parse("status!=Running:row:Fred", &["status"]).unwrap();
+        assert!(rule.predicate.column.as_deref() == Some("status"));
+        assert!(matches!(rule.scope, Scope::Row));
+        assert_eq!(rule.style, "Fred");
+    }
+
+    #[test]
+    fn parses_a_cell_scoped_rule() {
+        let rule = StyleRule::parse("status==Running:cell:Fg", &["status"]).unwrap();
+        assert!(matches!(rule.scope, Scope::Cell));
+        assert_eq!(rule.style, "Fg");
+    }
+
+    #[test]
+    fn rejects_a_rule_missing_the_scope_and_style() {
+        assert!(StyleRule::parse("status==Running", &["status"]).is_err());
+    }
+
+    #[test]
+    fn rejects_an_unknown_scope() {
+        match StyleRule::parse("status==Running:column:Fred", &["status"]) {
+            Err(err) => assert_eq!(err, "Unknown style rule scope: column"),
+            Ok(_) => panic!("expected an error"),
+        }
+    }
+
+    #[test]
+    fn rejects_an_unknown_column() {
+        assert!(StyleRule::parse("stauts==Running:row:Fred", &["status"]).is_err());
+    }
+
+    #[test]
+    fn row_scope_styles_every_cell_in_a_matching_row() {
+        let headers = ["name", "status"];
+        let rules = vec![StyleRule::parse("status!=Running:row:Fred", &headers).unwrap()];
+        let mut specs = vec![((), row(&headers, &["web-1", "Pending"]))];
+        apply_styles(&mut specs, &rules);
+        assert_eq!(specs[0].1[0].style, Some("Fred"));
+        assert_eq!(specs[0].1[1].style, Some("Fred"));
+    }
+
+    #[test]
+    fn cell_scope_only_styles_the_matching_column() {
+        let headers = ["name", "status"];
+        let rules = vec![StyleRule::parse("status==Running:cell:Fg", &headers).unwrap()];
+        let mut specs = vec![((), row(&headers, &["web-1", "Running"]))];
+        apply_styles(&mut specs, &rules);
+        assert_eq!(specs[0].1[0].style, None);
+        assert_eq!(specs[0].1[1].style, Some("Fg"));
+    }
+
+    #[test]
+    fn a_later_matching_rule_wins_over_an_earlier_one() {
+        let headers = ["status"];
+        let rules = vec![
+            StyleRule::parse("status==Running:row:Fg", &headers).unwrap(),
+            StyleRule::parse("status==Running:row:Fr", &headers).unwrap(),
+        ];
+        let mut specs = vec![((), row(&headers, &["Running"]))];
+        apply_styles(&mut specs, &rules);
+        assert_eq!(specs[0].1[0].style, Some("Fr"));
+    }
+}
+
+/// An in-place cell rewrite parsed from `pattern ==>> template`, optionally
+/// scoped to a named column via `col:pattern ==>> template`. `pattern` is a
+/// regex; `template` may reference its capture groups with `$1`/`${name}`,
+/// exactly as `Regex::replace_all` already supports in its replacement string.
+pub struct Transform {
+    column: Option<String>,
+    regex: Regex,
+    template: String,
+}
+
+/// Whether `s` looks like a column name rather than part of a regex, so
+/// `col:pattern ==>> template` can be told apart from an unscoped pattern
+/// whose text before its first `:` holds regex metacharacters (e.g.
+/// `\d{2}:\d{2}`). A prefix made only of letters, digits, `_` and `-` is
+/// always read as a column name, so `host:port` is scoped to column `host`.
+fn is_column_name(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+impl Transform {
+    pub fn parse(text: &str, headers: &[&str]) -> Result<Transform, String> {
+        let idx = text.find("==>>")
+            .ok_or_else(|| format!("Invalid transform (expected '==>>'): {}", text))?;
+        let lhs = text[..idx].trim();
+        let template = text[idx + "==>>".len()..].trim().to_string();
+
+        let (column, pattern) = match lhs.find(':') {
+            Some(idx) if is_column_name(lhs[..idx].trim()) => {
+                (Some(lhs[..idx].trim().to_string()), lhs[idx + 1..].trim())
+            }
+            _ => (None, lhs),
+        };
+
+        validate_column(headers, &column)?;
+        let regex = Regex::new(pattern).map_err(|_| format!("Invalid regex: {}", pattern))?;
+
+        Ok(Transform { column, regex, template })
+    }
+}
+
+/// Reads the repeated `--transform` argument (if any) into a list of [`Transform`]s.
+pub fn get_transforms(matches: &ArgMatches, headers: &[&str]) -> Result<Vec<Transform>, String> {
+    match matches.values_of("transform") {
+        Some(values) => values.map(|text| Transform::parse(text, headers)).collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Rewrites matching cell text in place, running after filtering but before
+/// `add_to_table`. Rewritten cells become owned `CellSpec`s so the borrowed
+/// lifetimes of the rest of the table stay valid.
+pub fn apply_transforms<'a, T>(specs: &mut Vec<(T, Vec<CellSpec<'a>>)>, transforms: &[Transform]) {
+    for t_spec in specs.iter_mut() {
+        for cell in t_spec.1.iter_mut() {
+            for transform in transforms {
+                let in_column = match &transform.column {
+                    Some(column) => cell.column == Some(column.as_str()),
+                    None => true,
+                };
+                if !in_column {
+                    continue;
+                }
+
+                let rewritten = cell.text()
+                    .map(|text| transform.regex.replace_all(text, transform.template.as_str()))
+                    .and_then(|rewritten| match rewritten {
+                        Cow::Owned(new_text) => Some(new_text),
+                        Cow::Borrowed(_) => None,
+                    });
+                if let Some(new_text) = rewritten {
+                    cell.set_text(new_text);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod transform_tests {
+    use super::*;
+
+    #[test]
+    fn parses_an_unscoped_transform() {
+        let transform = Transform::parse("web-.* ==>> web", &["Name"]).unwrap();
+        assert_eq!(transform.column, None);
+        assert_eq!(transform.template, "web");
+    }
+
+    #[test]
+    fn parses_a_column_scoped_transform() {
+        let transform = Transform::parse("Name:web-.* ==>> web", &["Name"]).unwrap();
+        assert_eq!(transform.column.as_deref(), Some("Name"));
+    }
+
+    #[test]
+    fn rejects_unknown_scoped_columns() {
+        assert!(Transform::parse("Nmae:web-.* ==>> web", &["Name"]).is_err());
+    }
+
+    #[test]
+    fn rejects_missing_arrow() {
+        assert!(Transform::parse("web-.* -> web", &["Name"]).is_err());
+    }
+
+    #[test]
+    fn is_column_name_accepts_identifier_like_strings() {
+        assert!(is_column_name("status"));
+        assert!(is_column_name("restart_count"));
+        assert!(is_column_name("pod-name"));
+    }
+
+    #[test]
+    fn is_column_name_rejects_empty_and_regex_metacharacters() {
+        assert!(!is_column_name(""));
+        assert!(!is_column_name(r"\d{2}"));
+        assert!(!is_column_name("a.*b"));
+    }
+
+    #[test]
+    fn a_colon_inside_an_unscoped_regex_does_not_get_mistaken_for_a_column() {
+        // The prefix before the first ':' (`\d{2}`) contains regex metacharacters,
+        // so it can't be a column name and the whole thing stays one unscoped pattern.
+        let transform = Transform::parse(r"\d{2}:\d{2} ==>> TIME", &["Name"]).unwrap();
+        assert_eq!(transform.column, None);
+        assert!(transform.regex.is_match("12:30"));
+    }
 }
 
 pub fn add_to_table<'a, T>(table: &mut Table, specs: &Vec<(T, Vec<CellSpec<'a>>)>) {
@@ -135,3 +856,145 @@ pub fn add_to_table<'a, T>(table: &mut Table, specs: &Vec<(T, Vec<CellSpec<'a>>)
         table.add_row(Row::new(row_vec));
     }
 }
+
+/// The format a table of resources can be rendered as. `Table` is the
+/// default, human-oriented prettytable rendering; the rest are for scripting.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Json,
+    Yaml,
+    Csv,
+    Tsv,
+}
+
+impl OutputFormat {
+    fn from_str(format: &str) -> Result<OutputFormat, String> {
+        match format {
+            "table" => Ok(OutputFormat::Table),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" => Ok(OutputFormat::Yaml),
+            "csv" => Ok(OutputFormat::Csv),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(format!("Unknown output format: {}", format)),
+        }
+    }
+}
+
+/// Reads the `-o`/`--output` argument (if any), defaulting to `Table`.
+pub fn get_output_format(matches: &ArgMatches) -> Result<OutputFormat, String> {
+    match matches.value_of("output") {
+        Some(format) => OutputFormat::from_str(format),
+        None => Ok(OutputFormat::Table),
+    }
+}
+
+/// A table row as an ordered list of (column, value) pairs, in header order.
+/// Serializes as a map via the streaming `serialize_entry` protocol, so unlike
+/// `serde_json::Map` (which sorts keys without the `preserve_order` feature),
+/// its field order in the output always matches the table's column order.
+struct OrderedRow(Vec<(String, String)>);
+
+impl Serialize for OrderedRow {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (column, value) in &self.0 {
+            map.serialize_entry(column, value)?;
+        }
+        map.end()
+    }
+}
+
+fn to_rows<'a, T>(headers: &[&str], specs: &Vec<(T, Vec<CellSpec<'a>>)>) -> Vec<OrderedRow> {
+    specs.iter().enumerate().map(|(index, t_spec)| {
+        OrderedRow(
+            headers.iter().zip(t_spec.1.iter())
+                .map(|(header, spec)| ((*header).to_string(), spec.resolved_text(index)))
+                .collect()
+        )
+    }).collect()
+}
+
+fn escape_field(field: &str, delim: char) -> String {
+    if field.contains(delim) || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn to_delimited<'a, T>(headers: &[&str], specs: &Vec<(T, Vec<CellSpec<'a>>)>, delim: char) -> String {
+    let sep = delim.to_string();
+    let mut out = String::new();
+    out.push_str(&headers.iter().map(|h| escape_field(h, delim)).collect::<Vec<_>>().join(&sep));
+    out.push('\n');
+    for (index, t_spec) in specs.iter().enumerate() {
+        let fields: Vec<String> = t_spec.1.iter()
+            .map(|spec| escape_field(&spec.resolved_text(index), delim))
+            .collect();
+        out.push_str(&fields.join(&sep));
+        out.push('\n');
+    }
+    out
+}
+
+/// Renders a table of resources in the given [`OutputFormat`], skipping
+/// prettytable's ANSI style codes entirely outside of `Table` mode.
+pub fn serialize<'a, T>(headers: &[&str], specs: &Vec<(T, Vec<CellSpec<'a>>)>, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Table => {
+            let mut table = Table::new();
+            table.set_titles(Row::new(headers.iter().map(|h| Cell::new(h)).collect()));
+            add_to_table(&mut table, specs);
+            table.to_string()
+        }
+        OutputFormat::Json => {
+            serde_json::to_string_pretty(&to_rows(headers, specs)).unwrap_or_default()
+        }
+        OutputFormat::Yaml => {
+            serde_yaml::to_string(&to_rows(headers, specs)).unwrap_or_default()
+        }
+        OutputFormat::Csv => to_delimited(headers, specs, ','),
+        OutputFormat::Tsv => to_delimited(headers, specs, '\t'),
+    }
+}
+
+#[cfg(test)]
+mod output_format_tests {
+    use super::*;
+
+    #[test]
+    fn escape_field_passes_through_plain_text() {
+        assert_eq!(escape_field("Running", ','), "Running");
+    }
+
+    #[test]
+    fn escape_field_quotes_fields_containing_the_delimiter() {
+        assert_eq!(escape_field("a,b", ','), "\"a,b\"");
+        assert_eq!(escape_field("a,b", '\t'), "a,b");
+    }
+
+    #[test]
+    fn escape_field_doubles_embedded_quotes() {
+        assert_eq!(escape_field("say \"hi\"", ','), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn escape_field_quotes_fields_containing_newlines() {
+        assert_eq!(escape_field("line1\nline2", ','), "\"line1\nline2\"");
+    }
+
+    #[test]
+    fn json_and_yaml_preserve_header_order_even_when_not_alphabetical() {
+        let headers = ["Status", "Name"];
+        let specs: Vec<((), Vec<CellSpec>)> = vec![
+            ((), vec![CellSpec::new("Running"), CellSpec::new("web-1")]),
+        ];
+
+        let json = serialize(&headers, &specs, OutputFormat::Json);
+        assert!(json.find("Status").unwrap() < json.find("Name").unwrap());
+
+        let yaml = serialize(&headers, &specs, OutputFormat::Yaml);
+        assert!(yaml.find("Status").unwrap() < yaml.find("Name").unwrap());
+    }
+}